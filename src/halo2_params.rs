@@ -1,21 +1,40 @@
-use std::io::Write;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+};
 
 use crate::{aztec_crs::get_aztec_crs, errors::Error};
 use ark_std::log2;
 use halo2_proofs_axiom::{
     arithmetic::g_to_lagrange,
     halo2curves::{
-        bn256::{Bn256, Fq, Fq2, G1Affine, G2Affine},
-        group::prime::PrimeCurveAffine,
+        bn256::{Bn256, Fq, Fq2, Fr, G1Affine, G2Affine, G1},
+        ff::Field,
+        group::{prime::PrimeCurveAffine, Curve, Group},
         pairing::Engine,
         serde::SerdeObject,
-        CurveAffine,
+        CurveAffine, CurveExt,
+    },
+    poly::{
+        commitment::Params,
+        kzg::commitment::{ParamsKZG, ParamsVerifierKZG},
     },
-    poly::kzg::commitment::ParamsKZG,
     SerdeFormat,
 };
+use rand_core::{OsRng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-pub(crate) async fn constuct_halo2_params_from_aztec_crs(num_points: u32) -> Result<ParamsKZG<Bn256>,Error> {
+/// How many consecutive powers of tau to spot-check with a random linear combination
+/// when `verify` is set. Checking every point would cost one scalar mul per point; this
+/// window is enough to catch a corrupted/truncated download without that overhead.
+const CRS_CHECK_WINDOW: usize = 128;
+
+pub(crate) async fn constuct_halo2_params_from_aztec_crs(
+    num_points: u32,
+    verify: bool,
+) -> Result<ParamsKZG<Bn256>, Error> {
     let points_needed = pow2ceil(num_points);
     let (g1_data, g2_data) = get_aztec_crs(points_needed).await?;
 
@@ -24,17 +43,279 @@ pub(crate) async fn constuct_halo2_params_from_aztec_crs(num_points: u32) -> Res
     assert!(n == 1 << k);
 
     let mut g = vec![<<Bn256 as Engine>::G1Affine as PrimeCurveAffine>::generator()];
+    g.extend(decode_g1_points(&g1_data)?);
 
-    g.extend(g1_data.chunks(64).map(|g1| to_g1_point(g1)));
+    let g2 = <<Bn256 as Engine>::G2Affine as PrimeCurveAffine>::generator();
+    let s_g2 = to_g2_point(&g2_data)?;
 
-    let g_lagrange = g_to_lagrange(g.iter().map(|g| PrimeCurveAffine::to_curve(g)).collect(), k);
+    if verify {
+        verify_crs_consistency(&g, g2, s_g2)?;
+    }
 
-    let g2 = <<Bn256 as Engine>::G2Affine as PrimeCurveAffine>::generator();
-    let s_g2 = to_g2_point(&g2_data);
+    let g_lagrange = g_to_lagrange(to_projective(&g), k);
 
     Ok(params_kzg(k, g, g_lagrange, g2, s_g2))
 }
 
+/// Decodes every 64-byte G1 chunk of the downloaded CRS into a point. Parallelized with
+/// rayon behind the `parallel` feature, since each chunk is decoded independently; kept
+/// serial (and thread-free) otherwise so WASM targets, which have no thread pool, still
+/// build and run.
+#[cfg(feature = "parallel")]
+fn decode_g1_points(g1_data: &[u8]) -> Result<Vec<G1Affine>, Error> {
+    g1_data.par_chunks(64).map(to_g1_point).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn decode_g1_points(g1_data: &[u8]) -> Result<Vec<G1Affine>, Error> {
+    g1_data.chunks(64).map(to_g1_point).collect()
+}
+
+/// Batch-converts affine G1 points to projective form ahead of [`g_to_lagrange`],
+/// parallelized behind the `parallel` feature for the same reason as
+/// [`decode_g1_points`].
+#[cfg(feature = "parallel")]
+fn to_projective(g: &[G1Affine]) -> Vec<G1> {
+    g.par_iter().map(PrimeCurveAffine::to_curve).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn to_projective(g: &[G1Affine]) -> Vec<G1> {
+    g.iter().map(PrimeCurveAffine::to_curve).collect()
+}
+
+/// Checks that the downloaded powers of tau are internally consistent with `s_g2`,
+/// catching a corrupted or truncated Aztec CRS download before it silently becomes
+/// garbage parameters.
+///
+/// First confirms `e(g[1], g2) == e(g[0], s_g2)`, i.e. that the first power of tau
+/// matches the published `s·G2`. Then picks a `CRS_CHECK_WINDOW`-sized window of
+/// consecutive downloaded points at a pseudorandom offset into the full downloaded
+/// range (not just the first `CRS_CHECK_WINDOW` points — corruption or truncation can
+/// land anywhere in a multi-million-point download), samples a random challenge `r`,
+/// forms the random linear combinations `A = Σ r^i · g[offset+i]` and
+/// `B = Σ r^i · g[offset+i+1]`, and checks `e(A, s_g2) == e(B, g2)` to confirm every
+/// power in that window shares the same secret.
+fn verify_crs_consistency(g: &[G1Affine], g2: G2Affine, s_g2: G2Affine) -> Result<(), Error> {
+    if g.len() < 2 {
+        return Ok(());
+    }
+
+    if Bn256::pairing(&g[1], &g2) != Bn256::pairing(&g[0], &s_g2) {
+        return Err(Error::InvalidCrs(
+            "first power of tau is inconsistent with the published s*g2".into(),
+        ));
+    }
+
+    let window = (g.len() - 1).min(CRS_CHECK_WINDOW);
+    let max_offset = (g.len() - 1) - window;
+    let offset = if max_offset == 0 {
+        0
+    } else {
+        (OsRng.next_u64() as usize) % (max_offset + 1)
+    };
+
+    let r = Fr::random(OsRng);
+
+    let mut scalar = Fr::ONE;
+    let mut a = G1::identity();
+    let mut b = G1::identity();
+    for i in 0..window {
+        a += g[offset + i] * scalar;
+        b += g[offset + i + 1] * scalar;
+        scalar *= r;
+    }
+
+    if Bn256::pairing(&a.to_affine(), &s_g2) != Bn256::pairing(&b.to_affine(), &g2) {
+        return Err(Error::InvalidCrs(
+            "downloaded powers of tau are inconsistent with each other".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Downloads a CRS large enough for `max_k` and returns the full `ParamsKZG<Bn256>`.
+/// Circuits with a smaller `k <= max_k` can then reuse it via [`downsize`] instead of
+/// paying for a separate CRS download and `g_to_lagrange` conversion per circuit.
+///
+/// `verify` is forwarded to [`constuct_halo2_params_from_aztec_crs`]'s CRS consistency
+/// check; since this one download is reused across every circuit up to `max_k`, a
+/// corrupted CRS here has the widest possible blast radius, so callers should only pass
+/// `false` if they've already validated the CRS some other way.
+pub async fn construct_halo2_params_range(
+    max_k: u32,
+    verify: bool,
+) -> Result<ParamsKZG<Bn256>, Error> {
+    constuct_halo2_params_from_aztec_crs(1 << max_k, verify).await
+}
+
+/// Truncates an already-constructed `ParamsKZG<Bn256>` down to `new_k`, reusing the same
+/// downloaded G1 powers of tau and `g2`/`s_g2` and recomputing the Lagrange basis for the
+/// smaller domain, so a single download at `max_k` can serve every circuit with
+/// `k <= max_k`.
+pub fn downsize(params: &ParamsKZG<Bn256>, new_k: u32) -> ParamsKZG<Bn256> {
+    let mut downsized = params.clone();
+    downsized.downsize(new_k);
+    downsized
+}
+
+/// Generates deterministic, offline KZG parameters for **testing only**.
+///
+/// The toxic-waste secret `s` is derived deterministically by hashing a fixed domain
+/// separator to the BN256 G1 curve and decoding the result as a scalar (see
+/// [`derive_insecure_secret`]), instead of sampling from a trusted setup. `g[i]` is then
+/// the real power `s^i · G`, and `s_g2 = s · g2`, so the params satisfy the same KZG
+/// pairing relations a genuine trusted setup would — there is simply no secrecy, since
+/// `s` is public and reproducible. There is no trusted setup and **no soundness
+/// guarantee** — these params must never be used outside of tests/CI, where they let the
+/// full prove/verify pipeline run without any network access.
+pub fn construct_insecure_params(k: u32) -> ParamsKZG<Bn256> {
+    let n = 1u64 << k;
+    let s = derive_insecure_secret();
+
+    let generator = <<Bn256 as Engine>::G1Affine as PrimeCurveAffine>::generator().to_curve();
+    let mut power = Fr::ONE;
+    let mut g_proj: Vec<G1> = Vec::with_capacity(n as usize);
+    for _ in 0..n {
+        g_proj.push(generator * power);
+        power *= s;
+    }
+
+    let mut g = vec![G1Affine::identity(); n as usize];
+    G1::batch_normalize(&g_proj, &mut g);
+
+    let g_lagrange = g_to_lagrange(g_proj, k);
+
+    let g2 = <<Bn256 as Engine>::G2Affine as PrimeCurveAffine>::generator();
+    let s_g2 = (g2 * s).to_affine();
+
+    params_kzg(k, g, g_lagrange, g2, s_g2)
+}
+
+/// Deterministically derives the insecure toxic-waste secret `s` used by
+/// [`construct_insecure_params`]: hashes a fixed domain separator (plus an incrementing
+/// counter, in the vanishingly unlikely case the first candidate maps to zero or fails
+/// to decode as a scalar) to a BN256 G1 point, then decodes that point's x-coordinate
+/// bytes as a scalar field element `Fr` (an x-coordinate not below the `Fr` modulus is
+/// rejected, not reduced).
+fn derive_insecure_secret() -> Fr {
+    let hasher = G1::hash_to_curve("Halo2-Parameters-secret");
+    let mut counter: u64 = 0;
+    loop {
+        let point = hasher(&counter.to_le_bytes()).to_affine();
+        let candidate: Option<Fr> = Option::from(point.coordinates())
+            .and_then(|coords| Option::from(Fr::from_bytes(&coords.x().to_bytes())));
+
+        if let Some(s) = candidate {
+            if !bool::from(s.is_zero()) {
+                return s;
+            }
+        }
+        counter += 1;
+    }
+}
+
+/// Builds only the parameters a verifier needs — `g[0]`, `g2`, and `s·g2` — without
+/// downloading the full G1 CRS or computing a Lagrange basis, mirroring Halo2's
+/// `ParamsKZG::into_verifier_params`. This is the cheap path for deployments that only
+/// ever verify proofs.
+pub async fn construct_halo2_verifier_params_from_aztec_crs(
+    k: u32,
+) -> Result<ParamsVerifierKZG<Bn256>, Error> {
+    let (_, g2_data) = get_aztec_crs(1).await?;
+    let s_g2 = to_g2_point(&g2_data)?;
+    verifier_params_kzg(k, s_g2)
+}
+
+/// Builds a `ParamsVerifierKZG<Bn256>` from just `k` and `s_g2` (`g[0]` and `g2` are
+/// always the fixed curve generators).
+///
+/// This assumes `ParamsVerifierKZG`'s on-disk layout is the minimal `(k, g, g2, s_g2)`
+/// buffer written below, not the full `ParamsKZG` layout (which would expect `2^k`
+/// entries each for `g` and `g_lagrange`). That assumption is checked two ways: the
+/// `read_custom` call itself is guarded with `catch_unwind`, since a wrong assumption
+/// can make it read past the end of `buf` and panic, and the freshly-built params are
+/// then round-tripped back through `write_custom` to catch a wrong-but-non-panicking
+/// parse. Either failure mode surfaces as the documented `Result::Err` instead of a
+/// panic or silently misparsed params.
+fn verifier_params_kzg(k: u32, s_g2: G2Affine) -> Result<ParamsVerifierKZG<Bn256>, Error> {
+    let g = <<Bn256 as Engine>::G1Affine as PrimeCurveAffine>::generator();
+    let g2 = <<Bn256 as Engine>::G2Affine as PrimeCurveAffine>::generator();
+
+    let mut buf: Vec<u8> = Vec::new();
+    buf.write(&k.to_le_bytes()).unwrap();
+    g.write_raw(&mut buf).unwrap();
+    g2.write_raw(&mut buf).unwrap();
+    s_g2.write_raw(&mut buf).unwrap();
+
+    let layout_error = || {
+        Error::InvalidCrs(
+            "ParamsVerifierKZG on-disk layout does not match the assumed (k, g, g2, s_g2) format"
+                .into(),
+        )
+    };
+
+    let prev_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let read_result = std::panic::catch_unwind(|| {
+        ParamsVerifierKZG::<Bn256>::read_custom(&mut &buf[..], SerdeFormat::RawBytesUnchecked)
+    });
+    std::panic::set_hook(prev_hook);
+    let params = read_result.map_err(|_| layout_error())?;
+
+    let mut roundtrip = Vec::new();
+    params.write_custom(&mut roundtrip, SerdeFormat::RawBytesUnchecked)?;
+    if roundtrip != buf {
+        return Err(layout_error());
+    }
+
+    Ok(params)
+}
+
+/// Loads a previously [`save_params`]-ed `ParamsKZG<Bn256>` from `path`, falling back to
+/// constructing it from the Aztec CRS on a cache miss and writing the result back out so
+/// subsequent calls can skip the network fetch and `g_to_lagrange` entirely.
+pub async fn constuct_halo2_params_cached(
+    num_points: u32,
+    path: impl AsRef<Path>,
+    format: SerdeFormat,
+    verify: bool,
+) -> Result<ParamsKZG<Bn256>, Error> {
+    let path = path.as_ref();
+    if path.exists() {
+        return load_params(path, format);
+    }
+
+    let params = constuct_halo2_params_from_aztec_crs(num_points, verify).await?;
+    save_params(&params, path, format)?;
+    Ok(params)
+}
+
+/// Writes a fully-constructed `ParamsKZG<Bn256>` to `path` using Halo2's `write_custom`,
+/// so the expensive CRS download and `g_to_lagrange` conversion only need to happen once.
+pub fn save_params(
+    params: &ParamsKZG<Bn256>,
+    path: impl AsRef<Path>,
+    format: SerdeFormat,
+) -> Result<(), Error> {
+    let file = File::create(path)?;
+    let mut writer = BufWriter::new(file);
+    params.write_custom(&mut writer, format)?;
+    Ok(())
+}
+
+/// Reads a `ParamsKZG<Bn256>` previously written by [`save_params`] back from disk.
+///
+/// `format` must match the `SerdeFormat` the params were saved with: `RawBytesUnchecked`
+/// for the fastest load, `RawBytes` to re-validate that every point is on-curve, or
+/// `Processed` for the portable/compressed encoding.
+pub fn load_params(path: impl AsRef<Path>, format: SerdeFormat) -> Result<ParamsKZG<Bn256>, Error> {
+    let file = File::open(path)?;
+    let mut reader = BufReader::new(file);
+    Ok(ParamsKZG::<Bn256>::read_custom(&mut reader, format))
+}
+
 /// Constructs a `ParamsKZG<Bn256>` from its parameters
 fn params_kzg(
     k: u32,
@@ -67,7 +348,7 @@ fn params_kzg(
     ParamsKZG::<Bn256>::read_custom(&mut &buf[..], SerdeFormat::RawBytesUnchecked)
 }
 
-fn to_g1_point(point: &[u8]) -> G1Affine {
+fn to_g1_point(point: &[u8]) -> Result<G1Affine, Error> {
     let le_bytes: Vec<u8> = point
         .chunks(8)
         .map(|limb| {
@@ -89,14 +370,16 @@ fn to_g1_point(point: &[u8]) -> G1Affine {
         }
     }
 
-    G1Affine::from_xy(
-        Fq::from_bytes(&first_byte_array).unwrap(),
-        Fq::from_bytes(&second_byte_array).unwrap(),
-    )
-    .unwrap()
+    let x = Option::<Fq>::from(Fq::from_bytes(&first_byte_array))
+        .ok_or_else(|| Error::InvalidCrs("G1 point x-coordinate is not a valid field element".into()))?;
+    let y = Option::<Fq>::from(Fq::from_bytes(&second_byte_array))
+        .ok_or_else(|| Error::InvalidCrs("G1 point y-coordinate is not a valid field element".into()))?;
+
+    Option::from(G1Affine::from_xy(x, y))
+        .ok_or_else(|| Error::InvalidCrs("G1 point is not on the curve".into()))
 }
 
-fn to_g2_point(point: &[u8]) -> G2Affine {
+fn to_g2_point(point: &[u8]) -> Result<G2Affine, Error> {
     let le_bytes: Vec<u8> = point
         .chunks(8)
         .map(|limb| {
@@ -118,13 +401,195 @@ fn to_g2_point(point: &[u8]) -> G2Affine {
         }
     }
 
-    G2Affine::from_xy(
-        Fq2::from_bytes(&first_byte_array).unwrap(),
-        Fq2::from_bytes(&second_byte_array).unwrap(),
-    )
-    .unwrap()
+    let x = Option::<Fq2>::from(Fq2::from_bytes(&first_byte_array))
+        .ok_or_else(|| Error::InvalidCrs("G2 point x-coordinate is not a valid field element".into()))?;
+    let y = Option::<Fq2>::from(Fq2::from_bytes(&second_byte_array))
+        .ok_or_else(|| Error::InvalidCrs("G2 point y-coordinate is not a valid field element".into()))?;
+
+    Option::from(G2Affine::from_xy(x, y))
+        .ok_or_else(|| Error::InvalidCrs("G2 point is not on the curve".into()))
 }
 
 fn pow2ceil(v: u32) -> u32 {
     v.next_power_of_two()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Commits to a small polynomial with `construct_insecure_params`, opens it at a
+    /// challenge point, and checks the KZG opening pairing equation
+    /// `e(C - y*g[0], g2) == e(W, s_g2 - z*g2)` — the same equation a real Halo2 proof's
+    /// verifier relies on. This is the regression test for the bug where `g` was built
+    /// from unrelated hash outputs instead of consecutive powers of a single secret `s`:
+    /// with that bug this pairing check fails for any non-constant polynomial.
+    #[test]
+    fn insecure_params_support_kzg_commit_and_open() {
+        let params = construct_insecure_params(4);
+        let g = params.get_g();
+
+        // p(X) = 3 + 5X + 7X^2
+        let coeffs = vec![Fr::from(3u64), Fr::from(5u64), Fr::from(7u64)];
+
+        let commitment = msm(&coeffs, g);
+
+        let z = Fr::from(11u64);
+        let y = eval(&coeffs, z);
+        let quotient = divide_by_linear(&coeffs, y, z);
+        let proof = msm(&quotient, g);
+
+        let lhs_g1 = (commitment - g[0] * y).to_affine();
+        let rhs_g2 = (params.s_g2().to_curve() - params.g2() * z).to_affine();
+
+        assert_eq!(
+            Bn256::pairing(&lhs_g1, &params.g2()),
+            Bn256::pairing(&proof.to_affine(), &rhs_g2),
+        );
+    }
+
+    /// `downsize` must produce params that (a) carry the exact same powers of tau as a
+    /// fresh `construct_insecure_params` build at the smaller `k` (since both reuse the
+    /// same deterministic secret `s`), and (b) still support a real KZG commit/open over
+    /// the truncated monomial basis `g`. The recomputed Lagrange basis is not compared
+    /// here.
+    #[test]
+    fn downsize_matches_direct_build_and_supports_kzg_commit_and_open() {
+        let k = 6;
+        let big = construct_insecure_params(k);
+        let downsized = downsize(&big, k - 1);
+        let direct = construct_insecure_params(k - 1);
+
+        assert_eq!(downsized.get_g(), direct.get_g());
+        assert_eq!(downsized.g2(), direct.g2());
+        assert_eq!(downsized.s_g2(), direct.s_g2());
+
+        let g = downsized.get_g();
+        let coeffs = vec![Fr::from(2u64), Fr::from(9u64), Fr::from(4u64)];
+        let commitment = msm(&coeffs, g);
+
+        let z = Fr::from(13u64);
+        let y = eval(&coeffs, z);
+        let quotient = divide_by_linear(&coeffs, y, z);
+        let proof = msm(&quotient, g);
+
+        let lhs_g1 = (commitment - g[0] * y).to_affine();
+        let rhs_g2 = (downsized.s_g2().to_curve() - downsized.g2() * z).to_affine();
+
+        assert_eq!(
+            Bn256::pairing(&lhs_g1, &downsized.g2()),
+            Bn256::pairing(&proof.to_affine(), &rhs_g2),
+        );
+    }
+
+    fn msm(scalars: &[Fr], bases: &[G1Affine]) -> G1 {
+        scalars
+            .iter()
+            .zip(bases.iter())
+            .fold(G1::identity(), |acc, (s, b)| acc + *b * s)
+    }
+
+    fn eval(coeffs: &[Fr], x: Fr) -> Fr {
+        coeffs.iter().rev().fold(Fr::ZERO, |acc, c| acc * x + *c)
+    }
+
+    /// Synthetic division of `p(X) - y` by `(X - z)`, assuming `y == p(z)` so the
+    /// division is exact.
+    fn divide_by_linear(coeffs: &[Fr], y: Fr, z: Fr) -> Vec<Fr> {
+        let mut c = coeffs.to_vec();
+        c[0] -= y;
+
+        let d = c.len() - 1;
+        let mut quotient = vec![Fr::ZERO; d];
+        let mut acc = c[d];
+        if d > 0 {
+            quotient[d - 1] = acc;
+        }
+        for i in (1..d).rev() {
+            acc = c[i] + z * acc;
+            quotient[i - 1] = acc;
+        }
+        quotient
+    }
+
+    /// A corrupted/truncated-style CRS point (not a genuine power of `s`) must be
+    /// rejected by the consistency check rather than silently accepted.
+    #[test]
+    fn verify_crs_consistency_rejects_corrupted_point() {
+        let params = construct_insecure_params(4);
+        let mut g = params.get_g().to_vec();
+        let shift = <<Bn256 as Engine>::G1Affine as PrimeCurveAffine>::generator().to_curve();
+        g[2] = (PrimeCurveAffine::to_curve(&g[2]) + shift).to_affine();
+
+        let err = verify_crs_consistency(&g, params.g2(), params.s_g2())
+            .expect_err("corrupted CRS point must fail the consistency check");
+        assert!(matches!(err, Error::InvalidCrs(_)));
+    }
+
+    /// Builds verifier-only params directly (no network) and checks the resulting
+    /// object actually carries the `s_g2` we fed in, rather than silently misparsed
+    /// bytes — the scenario the `verifier_params_kzg` round-trip self-check guards
+    /// against if `ParamsVerifierKZG`'s on-disk layout ever diverges from what's
+    /// assumed here.
+    #[test]
+    fn verifier_params_round_trip() {
+        let s_g2 = <<Bn256 as Engine>::G2Affine as PrimeCurveAffine>::generator();
+        let params = verifier_params_kzg(4, s_g2).expect("assumed ParamsVerifierKZG layout should hold");
+        assert_eq!(params.s_g2(), s_g2);
+    }
+
+    /// A unique-per-test path under the OS temp dir, so parallel test runs don't clobber
+    /// each other's files.
+    fn temp_params_path(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "halo2_params_test_{label}_{}.bin",
+            std::process::id()
+        ))
+    }
+
+    /// Round-trips `construct_insecure_params` through `save_params`/`load_params` for
+    /// both a raw and a processed `SerdeFormat`, checking the loaded params carry the
+    /// same `g`/`g2`/`s_g2` as the originals.
+    #[test]
+    fn params_round_trip_through_disk() {
+        let params = construct_insecure_params(4);
+
+        for (label, format) in [
+            ("raw", SerdeFormat::RawBytesUnchecked),
+            ("processed", SerdeFormat::Processed),
+        ] {
+            let path = temp_params_path(label);
+            save_params(&params, &path, format).expect("save_params should succeed");
+            let loaded = load_params(&path, format).expect("load_params should succeed");
+            std::fs::remove_file(&path).ok();
+
+            assert_eq!(loaded.get_g(), params.get_g());
+            assert_eq!(loaded.g2(), params.g2());
+            assert_eq!(loaded.s_g2(), params.s_g2());
+        }
+    }
+
+    /// When the cache file already exists, `constuct_halo2_params_cached` must return
+    /// its contents directly rather than hitting the network — passing a `num_points`
+    /// that doesn't match what's on disk would only be harmless if the cache-hit branch
+    /// really does skip `constuct_halo2_params_from_aztec_crs` entirely.
+    #[tokio::test]
+    async fn cached_params_takes_cache_hit_path() {
+        let path = temp_params_path("cache_hit");
+        let params = construct_insecure_params(4);
+        save_params(&params, &path, SerdeFormat::RawBytesUnchecked).expect("save_params should succeed");
+
+        let loaded = constuct_halo2_params_cached(
+            1 << 20,
+            &path,
+            SerdeFormat::RawBytesUnchecked,
+            false,
+        )
+        .await
+        .expect("cache hit should not need the network");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.get_g(), params.get_g());
+        assert_eq!(loaded.s_g2(), params.s_g2());
+    }
+}